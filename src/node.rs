@@ -1,12 +1,63 @@
+use std::fmt;
+use std::ops::Range;
+
+use tree_sitter::InputEdit as OtherInputEdit;
 use tree_sitter::Node as OtherNode;
+use tree_sitter::Point;
 use tree_sitter::Tree as OtherTree;
 use tree_sitter::{Parser, TreeCursor};
 
 use crate::checker::Checker;
 use crate::traits::{LanguageInfo, Search};
 
+/// Shared helpers for building a real parsed tree in this module's tests,
+/// so traversal logic is exercised against actual `AST` shapes rather than
+/// hand-rolled `tree_sitter::Node` stand-ins.
+#[cfg(test)]
+mod test_support {
+    use tree_sitter::{Parser, Tree};
+
+    pub(super) fn parse_json(code: &str) -> Tree {
+        let mut parser = Parser::new();
+        parser.set_language(&tree_sitter_json::language()).unwrap();
+        parser.parse(code, None).unwrap()
+    }
+}
+
+/// Describes an edit made to a source file, passed to [`Tree::edit`] so
+/// that [`Tree::reparse`] can reuse the unaffected parts of the old tree.
+#[derive(Clone, Copy, Debug)]
+pub struct InputEdit {
+    /// The byte offset where the edit starts.
+    pub start_byte: usize,
+    /// The byte offset of the end of the old (pre-edit) text.
+    pub old_end_byte: usize,
+    /// The byte offset of the end of the new (post-edit) text.
+    pub new_end_byte: usize,
+    /// The (row, column) position where the edit starts.
+    pub start_position: (usize, usize),
+    /// The (row, column) position of the end of the old (pre-edit) text.
+    pub old_end_position: (usize, usize),
+    /// The (row, column) position of the end of the new (post-edit) text.
+    pub new_end_position: (usize, usize),
+}
+
+impl InputEdit {
+    fn to_ts(self) -> OtherInputEdit {
+        OtherInputEdit {
+            start_byte: self.start_byte,
+            old_end_byte: self.old_end_byte,
+            new_end_byte: self.new_end_byte,
+            start_position: Point::new(self.start_position.0, self.start_position.1),
+            old_end_position: Point::new(self.old_end_position.0, self.old_end_position.1),
+            new_end_position: Point::new(self.new_end_position.0, self.new_end_position.1),
+        }
+    }
+}
+
+/// A parsed `AST`, and the entry point for incremental reparsing.
 #[derive(Clone, Debug)]
-pub(crate) struct Tree(OtherTree);
+pub struct Tree(OtherTree);
 
 impl Tree {
     pub(crate) fn new<T: LanguageInfo>(code: &[u8]) -> Self {
@@ -18,16 +69,63 @@ impl Tree {
         Self(parser.parse(code, None).unwrap())
     }
 
-    pub(crate) fn get_root(&self) -> Node<'_> {
+    /// Returns the root node of this tree.
+    pub fn get_root(&self) -> Node<'_> {
         Node(self.0.root_node())
     }
+
+    /// Shifts this tree's node offsets to account for `edit`, in
+    /// preparation for [`Tree::reparse`].
+    pub fn edit(&mut self, edit: InputEdit) {
+        self.0.edit(&edit.to_ts());
+    }
+
+    /// Reparses `new_code` reusing the unchanged parts of this tree.
+    ///
+    /// This tree must have been updated with [`Tree::edit`] for every
+    /// edit applied to `new_code` since it was parsed, otherwise
+    /// tree-sitter can't tell which subtrees are still valid.
+    pub fn reparse<T: LanguageInfo>(&self, new_code: &[u8]) -> Self {
+        let mut parser = Parser::new();
+        parser
+            .set_language(&T::get_lang().get_ts_language())
+            .unwrap();
+
+        Self(parser.parse(new_code, Some(&self.0)).unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tree_tests {
+    use super::*;
+
+    #[test]
+    fn input_edit_converts_to_tree_sitter_points() {
+        let edit = InputEdit {
+            start_byte: 4,
+            old_end_byte: 5,
+            new_end_byte: 7,
+            start_position: (0, 4),
+            old_end_position: (0, 5),
+            new_end_position: (0, 7),
+        };
+
+        let ts_edit = edit.to_ts();
+
+        assert_eq!(ts_edit.start_byte, 4);
+        assert_eq!(ts_edit.old_end_byte, 5);
+        assert_eq!(ts_edit.new_end_byte, 7);
+        assert_eq!(ts_edit.start_position, Point::new(0, 4));
+        assert_eq!(ts_edit.old_end_position, Point::new(0, 5));
+        assert_eq!(ts_edit.new_end_position, Point::new(0, 7));
+    }
 }
 
 /// An `AST` node.
 ///
 /// The inner `tree_sitter::Node` is exposed for advanced use cases
 /// where direct access to the underlying tree-sitter API is needed.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Node<'a>(pub OtherNode<'a>);
 
 impl<'a> Node<'a> {
@@ -57,6 +155,14 @@ impl<'a> Node<'a> {
         self.0.utf8_text(data).ok()
     }
 
+    /// Returns a lazy, zero-copy [`SyntaxText`] view over this node's source range.
+    pub fn text(&self, data: &'a [u8]) -> SyntaxText<'a> {
+        SyntaxText {
+            data,
+            range: self.start_byte()..self.end_byte(),
+        }
+    }
+
     /// Returns the byte offset where this node starts.
     pub fn start_byte(&self) -> usize {
         self.0.start_byte()
@@ -229,6 +335,484 @@ impl<'a> Node<'a> {
         }
         Some(node)
     }
+
+    /// Finds the smallest leaf covering `offset`, useful for mapping an
+    /// editor cursor position (hover, selection expansion, ...) onto the
+    /// `AST`.
+    ///
+    /// Returns [`TokenAtOffset::None`] if `offset` falls outside this
+    /// node's range, [`TokenAtOffset::Single`] if it falls strictly
+    /// inside one leaf, and [`TokenAtOffset::Between`] if it sits
+    /// exactly on the boundary between two adjacent leaves. An offset in
+    /// a gap that no child covers (such as whitespace between tokens)
+    /// resolves to the nearest leaves around it: `Between` when there is
+    /// a leaf on each side, `Single` when there is one on one side only.
+    pub fn token_at_offset(&self, offset: usize) -> TokenAtOffset<Node<'a>> {
+        if offset < self.start_byte() || offset > self.end_byte() {
+            return TokenAtOffset::None;
+        }
+
+        let mut node = *self;
+        loop {
+            if node.child_count() == 0 {
+                return TokenAtOffset::Single(node);
+            }
+
+            let matches: Vec<Node<'a>> = node
+                .children()
+                .filter(|child| child.start_byte() <= offset && offset <= child.end_byte())
+                .collect();
+
+            match matches.as_slice() {
+                [] => return node.nearest_leaves_around(offset),
+                [only] if only.child_count() == 0 => return TokenAtOffset::Single(*only),
+                [only] => node = *only,
+                [first, .., last] => {
+                    let left = first.rightmost_leaf_ending_at(offset);
+                    let right = last.leftmost_leaf_starting_at(offset);
+                    return TokenAtOffset::Between(left, right);
+                }
+            }
+        }
+    }
+
+    /// Resolves `offset` when it falls in a gap between this node's
+    /// children that no child's range covers (e.g. the whitespace
+    /// between two statements), by finding the nearest leaves on
+    /// either side of the gap.
+    fn nearest_leaves_around(&self, offset: usize) -> TokenAtOffset<Node<'a>> {
+        let mut left = None;
+        let mut right = None;
+        for child in self.children() {
+            if child.end_byte() <= offset {
+                left = Some(child);
+            } else if right.is_none() {
+                right = Some(child);
+            }
+        }
+
+        match (left, right) {
+            (Some(left), Some(right)) => {
+                TokenAtOffset::Between(left.rightmost_leaf(), right.leftmost_leaf())
+            }
+            (Some(left), None) => TokenAtOffset::Single(left.rightmost_leaf()),
+            (None, Some(right)) => TokenAtOffset::Single(right.leftmost_leaf()),
+            (None, None) => TokenAtOffset::Single(*self),
+        }
+    }
+
+    fn rightmost_leaf(&self) -> Node<'a> {
+        let mut node = *self;
+        while let Some(last) = node.children().last() {
+            node = last;
+        }
+        node
+    }
+
+    fn leftmost_leaf(&self) -> Node<'a> {
+        let mut node = *self;
+        while let Some(first) = node.children().next() {
+            node = first;
+        }
+        node
+    }
+
+    /// Returns the smallest descendant of this node (possibly this node
+    /// itself) whose byte range spans `offset`, or `None` if `offset`
+    /// falls outside this node's range.
+    pub fn descendant_at_offset(&self, offset: usize) -> Option<Node<'a>> {
+        if offset < self.start_byte() || offset > self.end_byte() {
+            return None;
+        }
+        let mut node = *self;
+        while let Some(child) = node
+            .children()
+            .find(|child| child.start_byte() <= offset && offset <= child.end_byte())
+        {
+            node = child;
+        }
+        Some(node)
+    }
+
+    fn rightmost_leaf_ending_at(&self, offset: usize) -> Node<'a> {
+        let mut node = *self;
+        loop {
+            let kids: Vec<Node<'a>> = node.children().collect();
+            match kids
+                .into_iter()
+                .rev()
+                .find(|child| child.end_byte() == offset)
+            {
+                Some(child) => node = child,
+                None => break,
+            }
+        }
+        node
+    }
+
+    fn leftmost_leaf_starting_at(&self, offset: usize) -> Node<'a> {
+        let mut node = *self;
+        while let Some(child) = node.children().find(|child| child.start_byte() == offset) {
+            node = child;
+        }
+        node
+    }
+
+    /// Returns an iterator over this node and each ancestor, ending at the root.
+    pub fn ancestors(&self) -> Ancestors<'a> {
+        Ancestors(Some(*self))
+    }
+
+    /// Returns a preorder iterator over this node and its descendants.
+    pub fn descendants(&self) -> Descendants<'a> {
+        Descendants(self.preorder())
+    }
+
+    /// Returns an iterator over this node's siblings, starting with this
+    /// node itself and walking in `direction`.
+    pub fn siblings(&self, direction: Direction) -> Siblings<'a> {
+        Siblings {
+            next: Some(*self),
+            direction,
+        }
+    }
+}
+
+#[cfg(test)]
+mod offset_tests {
+    use super::test_support::parse_json;
+    use super::*;
+
+    #[test]
+    fn token_at_offset_is_single_strictly_inside_a_leaf() {
+        let code = "{\"ab\": 1, \"c\": 2}";
+        let tree = parse_json(code);
+        let root = Node(tree.root_node());
+
+        // Offset 3 is strictly inside the "ab" string_content leaf (2..4).
+        match root.token_at_offset(3) {
+            TokenAtOffset::Single(n) => assert_eq!(n.child_count(), 0),
+            other => panic!("expected Single leaf, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn token_at_offset_resolves_whitespace_gaps_to_surrounding_leaves() {
+        // Two spaces after the comma so the gap offset (10) falls strictly
+        // between the comma's end (9) and the next string's start (11),
+        // a byte no child's inclusive range covers.
+        let code = "{\"ab\": 1,  \"c\": 2}";
+        let tree = parse_json(code);
+        let root = Node(tree.root_node());
+
+        assert_eq!(&code[8..9], ",");
+        assert_eq!(&code[10..11], " ");
+        match root.token_at_offset(10) {
+            TokenAtOffset::Between(left, right) => {
+                assert_eq!(left.child_count(), 0);
+                assert_eq!(right.child_count(), 0);
+                assert!(left.end_byte() <= 10);
+                assert!(right.start_byte() >= 10);
+            }
+            other => panic!("expected Between for a whitespace gap, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn token_at_offset_is_none_outside_the_node_range() {
+        let tree = parse_json("{}");
+        let root = Node(tree.root_node());
+        assert_eq!(root.token_at_offset(100), TokenAtOffset::None);
+    }
+
+    #[test]
+    fn descendant_at_offset_matches_the_inclusive_boundary_of_token_at_offset() {
+        let code = "{\"ab\": 1, \"c\": 2}";
+        let tree = parse_json(code);
+        let root = Node(tree.root_node());
+
+        // At the boundary between two leaves, descendant_at_offset returns
+        // the smallest node whose range still contains it, so it agrees
+        // with one side of token_at_offset's Between.
+        let boundary = 8;
+        let descendant = root.descendant_at_offset(boundary).unwrap();
+        match root.token_at_offset(boundary) {
+            TokenAtOffset::Between(left, right) => {
+                assert!(descendant == left || descendant == right);
+            }
+            TokenAtOffset::Single(leaf) => assert_eq!(descendant, leaf),
+            TokenAtOffset::None => panic!("offset is inside the root's range"),
+        }
+    }
+}
+
+/// A direction in which to walk a node's siblings, used by
+/// [`Node::siblings`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    /// Walk towards later siblings.
+    Next,
+    /// Walk towards earlier siblings.
+    Prev,
+}
+
+/// Iterator returned by [`Node::ancestors`].
+pub struct Ancestors<'a>(Option<Node<'a>>);
+
+impl<'a> Iterator for Ancestors<'a> {
+    type Item = Node<'a>;
+
+    fn next(&mut self) -> Option<Node<'a>> {
+        let node = self.0.take()?;
+        self.0 = node.parent();
+        Some(node)
+    }
+}
+
+/// Iterator returned by [`Node::descendants`].
+pub struct Descendants<'a>(Preorder<'a>);
+
+impl<'a> Iterator for Descendants<'a> {
+    type Item = Node<'a>;
+
+    fn next(&mut self) -> Option<Node<'a>> {
+        loop {
+            match self.0.next()? {
+                WalkEvent::Enter(node) => return Some(node),
+                WalkEvent::Leave(_) => continue,
+            }
+        }
+    }
+}
+
+/// Iterator returned by [`Node::siblings`].
+pub struct Siblings<'a> {
+    next: Option<Node<'a>>,
+    direction: Direction,
+}
+
+impl<'a> Iterator for Siblings<'a> {
+    type Item = Node<'a>;
+
+    fn next(&mut self) -> Option<Node<'a>> {
+        let node = self.next.take()?;
+        self.next = match self.direction {
+            Direction::Next => node.next_sibling(),
+            Direction::Prev => node.previous_sibling(),
+        };
+        Some(node)
+    }
+}
+
+#[cfg(test)]
+mod traversal_tests {
+    use super::test_support::parse_json;
+    use super::*;
+
+    #[test]
+    fn ancestors_starts_at_self_and_ends_at_the_root() {
+        let tree = parse_json(r#"[1, 2, 3]"#);
+        let root = Node(tree.root_node());
+        let number = root
+            .descendants()
+            .find(|n| n.kind() == "number")
+            .expect("at least one number literal");
+
+        let chain: Vec<_> = number.ancestors().map(|n| n.kind()).collect();
+
+        assert_eq!(chain.first(), Some(&"number"));
+        assert_eq!(chain.last(), Some(&root.kind()));
+    }
+
+    #[test]
+    fn descendants_visits_the_root_first_in_preorder() {
+        let tree = parse_json(r#"{"a": [1, 2]}"#);
+        let root = Node(tree.root_node());
+
+        let all: Vec<_> = root.descendants().collect();
+
+        assert_eq!(all[0], root);
+        assert_eq!(
+            all.len(),
+            root.preorder()
+                .filter(|e| matches!(e, WalkEvent::Enter(_)))
+                .count()
+        );
+    }
+
+    #[test]
+    fn siblings_walk_forward_in_source_order() {
+        let tree = parse_json(r#"[1, 2, 3]"#);
+        let root = Node(tree.root_node());
+        let array = root.child(0).unwrap();
+        let first_number = array
+            .children()
+            .find(|n| n.kind() == "number")
+            .expect("at least one number literal");
+
+        let forward: Vec<_> = first_number
+            .siblings(Direction::Next)
+            .map(|n| n.start_byte())
+            .collect();
+
+        assert_eq!(forward[0], first_number.start_byte());
+        assert!(forward.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn siblings_walk_backward_towards_earlier_siblings() {
+        let tree = parse_json(r#"[1, 2, 3]"#);
+        let root = Node(tree.root_node());
+        let array = root.child(0).unwrap();
+        let last_number = array
+            .children()
+            .filter(|n| n.kind() == "number")
+            .last()
+            .expect("at least one number literal");
+
+        let backward: Vec<_> = last_number
+            .siblings(Direction::Prev)
+            .map(|n| n.start_byte())
+            .collect();
+
+        assert_eq!(backward[0], last_number.start_byte());
+        assert!(backward.windows(2).all(|w| w[0] > w[1]));
+    }
+}
+
+/// The result of [`Node::token_at_offset`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TokenAtOffset<T> {
+    /// The offset is outside the searched node's range.
+    None,
+    /// The offset lies strictly inside a single leaf, or in a gap with
+    /// a leaf on one side only.
+    Single(T),
+    /// The offset lies exactly on the boundary between two adjacent
+    /// leaves, or in a gap between two leaves.
+    Between(T, T),
+}
+
+/// A lazy, zero-copy view over a node's source range, obtained via
+/// [`Node::text`]. No allocation happens until a caller asks for an
+/// owned `String` (e.g. via `to_string()`).
+#[derive(Clone)]
+pub struct SyntaxText<'a> {
+    data: &'a [u8],
+    range: Range<usize>,
+}
+
+impl<'a> SyntaxText<'a> {
+    /// Returns the length in bytes of this text.
+    pub fn len(&self) -> usize {
+        self.range.len()
+    }
+
+    /// Returns `true` if this text is empty.
+    pub fn is_empty(&self) -> bool {
+        self.range.is_empty()
+    }
+
+    /// Returns this text as a `&str`, or `None` if it isn't valid UTF-8.
+    fn as_str(&self) -> Option<&'a str> {
+        std::str::from_utf8(&self.data[self.range.clone()]).ok()
+    }
+
+    /// Returns the character starting at byte `offset` relative to the
+    /// start of this text, if any.
+    pub fn char_at(&self, offset: usize) -> Option<char> {
+        self.as_str()?.get(offset..)?.chars().next()
+    }
+
+    /// Returns `true` if this text contains `c`.
+    pub fn contains_char(&self, c: char) -> bool {
+        self.as_str().is_some_and(|s| s.contains(c))
+    }
+
+    /// Returns the byte offset of the first occurrence of `pat` in this
+    /// text, relative to the start of this text, if any.
+    pub fn find(&self, pat: &str) -> Option<usize> {
+        self.as_str()?.find(pat)
+    }
+
+    /// Returns the sub-slice of this text corresponding to `range`
+    /// (relative to the start of this text), or `None` if `range` runs
+    /// past the end of this text or its start is after its end.
+    pub fn slice(&self, range: Range<usize>) -> Option<SyntaxText<'a>> {
+        if range.start > range.end || range.end > self.len() {
+            return None;
+        }
+        Some(SyntaxText {
+            data: self.data,
+            range: self.range.start + range.start..self.range.start + range.end,
+        })
+    }
+}
+
+impl PartialEq<&str> for SyntaxText<'_> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == Some(*other)
+    }
+}
+
+impl fmt::Debug for SyntaxText<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.as_str() {
+            Some(s) => fmt::Debug::fmt(s, f),
+            None => write!(f, "<invalid utf8>"),
+        }
+    }
+}
+
+impl fmt::Display for SyntaxText<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.as_str() {
+            Some(s) => fmt::Display::fmt(s, f),
+            None => write!(f, "<invalid utf8>"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod syntax_text_tests {
+    use super::*;
+
+    fn text(data: &[u8], range: Range<usize>) -> SyntaxText<'_> {
+        SyntaxText { data, range }
+    }
+
+    #[test]
+    fn slice_out_of_bounds_returns_none() {
+        let data = b"short";
+        let t = text(data, 0..data.len());
+        assert!(t.slice(2..1000).is_none());
+        assert!(t.slice(3..2).is_none());
+    }
+
+    #[test]
+    fn slice_within_bounds_is_relative_to_the_text() {
+        let data = b"hello world";
+        let t = text(data, 0..data.len());
+        let sliced = t.slice(6..11).unwrap();
+        assert_eq!(sliced, "world");
+    }
+
+    #[test]
+    fn multi_byte_utf8_find_and_char_at() {
+        let data = "héllo".as_bytes();
+        let t = text(data, 0..data.len());
+        assert_eq!(t.find("llo"), Some(3));
+        assert_eq!(t.char_at(1), Some('é'));
+        assert!(t.contains_char('é'));
+    }
+
+    #[test]
+    fn invalid_utf8_does_not_silently_match_as_empty() {
+        let data = [0x68, 0xff, 0x6c];
+        let t = text(&data, 0..data.len());
+        assert_ne!(t, "");
+        assert_eq!(t.find("h"), None);
+    }
 }
 
 /// An `AST` cursor.
@@ -253,7 +837,105 @@ impl<'a> Cursor<'a> {
     }
 }
 
+/// An event emitted while walking a tree in preorder, as produced by
+/// [`Node::preorder`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WalkEvent<T> {
+    /// Emitted when a node is first reached, before its children.
+    Enter(T),
+    /// Emitted once all of a node's descendants have been visited.
+    Leave(T),
+}
+
+type Event<'a> = WalkEvent<Node<'a>>;
+
+/// Iterator returned by [`Node::preorder`].
+pub struct Preorder<'a> {
+    cursor: Cursor<'a>,
+    stack: Vec<Event<'a>>,
+}
+
+impl<'a> Iterator for Preorder<'a> {
+    type Item = Event<'a>;
+
+    fn next(&mut self) -> Option<Event<'a>> {
+        let event = self.stack.pop()?;
+        if let WalkEvent::Enter(node) = event {
+            self.stack.push(WalkEvent::Leave(node));
+            self.cursor.reset(&node);
+            if self.cursor.goto_first_child() {
+                let mut children = Vec::new();
+                loop {
+                    children.push(self.cursor.node());
+                    if !self.cursor.goto_next_sibling() {
+                        break;
+                    }
+                }
+                for child in children.into_iter().rev() {
+                    self.stack.push(WalkEvent::Enter(child));
+                }
+            }
+        }
+        Some(event)
+    }
+}
+
+#[cfg(test)]
+mod preorder_tests {
+    use super::test_support::parse_json;
+    use super::*;
+
+    #[test]
+    fn enter_and_leave_are_balanced_and_nest_by_depth() {
+        let tree = parse_json(r#"{"a": [1, 2]}"#);
+        let root = Node(tree.root_node());
+
+        let mut depth = 0i32;
+        let mut events = Vec::new();
+        for event in root.preorder() {
+            match event {
+                WalkEvent::Enter(node) => {
+                    events.push((depth, node.kind()));
+                    depth += 1;
+                }
+                WalkEvent::Leave(_) => depth -= 1,
+            }
+        }
+
+        assert_eq!(depth, 0);
+        assert_eq!(events[0], (0, root.kind()));
+        assert!(events.iter().any(|&(depth, _)| depth == 1));
+    }
+
+    #[test]
+    fn leave_is_emitted_once_every_descendant_has_been_entered() {
+        let tree = parse_json(r#"[1, 2, 3]"#);
+        let root = Node(tree.root_node());
+
+        let mut entered = std::collections::HashSet::new();
+        for event in root.preorder() {
+            match event {
+                WalkEvent::Enter(node) => {
+                    entered.insert(node.id());
+                }
+                WalkEvent::Leave(node) => {
+                    for child in node.children() {
+                        assert!(entered.contains(&child.id()));
+                    }
+                }
+            }
+        }
+    }
+}
+
 impl<'a> Search<'a> for Node<'a> {
+    fn preorder(&self) -> Preorder<'a> {
+        Preorder {
+            cursor: self.cursor(),
+            stack: vec![Event::Enter(*self)],
+        }
+    }
+
     fn first_occurrence(&self, pred: fn(u16) -> bool) -> Option<Node<'a>> {
         let mut cursor = self.cursor();
         let mut stack = Vec::new();
@@ -345,3 +1027,104 @@ impl<'a> Search<'a> for Node<'a> {
         }
     }
 }
+
+/// An owned, `serde`-friendly structural representation of a subtree,
+/// built by [`Node::to_serializable`].
+#[cfg(feature = "serde")]
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct SerializableNode {
+    pub kind: String,
+    pub kind_id: u16,
+    pub start_byte: usize,
+    pub end_byte: usize,
+    pub start_position: (usize, usize),
+    pub end_position: (usize, usize),
+    /// The leaf's `utf8_text`, if requested and this node has no children.
+    pub text: Option<String>,
+    pub children: Vec<SerializableNode>,
+}
+
+#[cfg(feature = "serde")]
+impl<'a> Node<'a> {
+    /// Builds a [`SerializableNode`] for this subtree. Pass `data` to
+    /// inline `utf8_text` for leaves, or `None` to omit source text.
+    pub fn to_serializable(&self, data: Option<&'a [u8]>) -> SerializableNode {
+        let mut stack: Vec<(Node<'a>, Vec<SerializableNode>)> = Vec::new();
+        let mut root = None;
+
+        for event in self.preorder() {
+            match event {
+                WalkEvent::Enter(node) => stack.push((node, Vec::new())),
+                WalkEvent::Leave(node) => {
+                    let (node, children) = stack.pop().expect("unbalanced preorder walk");
+                    let text = if children.is_empty() {
+                        data.and_then(|data| node.utf8_text(data))
+                            .map(str::to_owned)
+                    } else {
+                        None
+                    };
+                    let serialized = SerializableNode {
+                        kind: node.kind().to_owned(),
+                        kind_id: node.kind_id(),
+                        start_byte: node.start_byte(),
+                        end_byte: node.end_byte(),
+                        start_position: node.start_position(),
+                        end_position: node.end_position(),
+                        text,
+                        children,
+                    };
+                    match stack.last_mut() {
+                        Some((_, parent_children)) => parent_children.push(serialized),
+                        None => root = Some(serialized),
+                    }
+                }
+            }
+        }
+
+        root.expect("preorder always emits a matching Leave event for the root")
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serializable_tests {
+    use super::test_support::parse_json;
+    use super::*;
+
+    #[test]
+    fn mirrors_the_tree_shape_and_inlines_leaf_text() {
+        let code = r#"{"a": 1}"#;
+        let tree = parse_json(code);
+        let root = Node(tree.root_node());
+
+        let serialized = root.to_serializable(Some(code.as_bytes()));
+
+        assert_eq!(serialized.kind, root.kind());
+        assert_eq!(serialized.start_byte, root.start_byte());
+        assert_eq!(serialized.end_byte, root.end_byte());
+        assert_eq!(serialized.children.len(), root.child_count());
+
+        let leaf = serialized
+            .children
+            .iter()
+            .flat_map(|c| c.children.iter())
+            .flat_map(|c| c.children.iter())
+            .find(|c| c.kind == "number")
+            .expect("the integer literal should be present");
+        assert_eq!(leaf.text.as_deref(), Some("1"));
+        assert!(leaf.children.is_empty());
+    }
+
+    #[test]
+    fn omits_text_when_no_source_data_is_given() {
+        let code = r#"[1, 2]"#;
+        let tree = parse_json(code);
+        let root = Node(tree.root_node());
+
+        let serialized = root.to_serializable(None);
+
+        assert!(serialized.text.is_none());
+        for child in &serialized.children {
+            assert!(child.text.is_none());
+        }
+    }
+}
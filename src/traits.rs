@@ -0,0 +1,27 @@
+use crate::node::{Node, Preorder};
+
+/// Structural search operations over an `AST` node.
+pub trait Search<'a> {
+    /// Returns a depth-aware preorder traversal of this node and all of
+    /// its descendants, emitting an `Enter` event before visiting a
+    /// node's children and a `Leave` event once all of them have been
+    /// visited.
+    fn preorder(&self) -> Preorder<'a>;
+
+    /// Returns the first node of this subtree, this node included (in
+    /// preorder), whose kind id matches `pred`.
+    fn first_occurrence(&self, pred: fn(u16) -> bool) -> Option<Node<'a>>;
+
+    /// Returns every node of this subtree, this node included (in
+    /// preorder), whose kind id matches `pred`.
+    fn all_occurrences(&self, pred: fn(u16) -> bool) -> Vec<Node<'a>>;
+
+    /// Runs `action` on this node and every descendant, in preorder.
+    fn act_on_node(&self, action: &mut dyn FnMut(&Node<'a>));
+
+    /// Returns the first direct child whose kind id matches `pred`.
+    fn first_child(&self, pred: fn(u16) -> bool) -> Option<Node<'a>>;
+
+    /// Runs `action` on each direct child of this node.
+    fn act_on_child(&self, action: &mut dyn FnMut(&Node<'a>));
+}